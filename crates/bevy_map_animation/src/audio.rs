@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// Audio cue carried by a [`crate::TriggerDef`]. `bevy_map_runtime` spawns a
+/// Bevy `AudioPlayer`/`AudioSource` from this the moment the trigger fires,
+/// so named triggers can play SFX with zero per-app code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AudioTrigger {
+    /// Asset path, relative to the assets folder (e.g. `"sfx/croak.ogg"`).
+    pub asset: String,
+    #[serde(default = "AudioTrigger::default_volume")]
+    pub volume: f32,
+    #[serde(default = "AudioTrigger::default_pitch")]
+    pub pitch: f32,
+    /// When true, the sound falls off with distance from the listener
+    /// relative to the sprite's `Transform` instead of playing at full volume.
+    #[serde(default)]
+    pub spatial: bool,
+}
+
+impl AudioTrigger {
+    fn default_volume() -> f32 {
+        1.0
+    }
+
+    fn default_pitch() -> f32 {
+        1.0
+    }
+}
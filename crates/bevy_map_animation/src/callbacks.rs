@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+
+type TriggerSystemId = SystemId<In<(Entity, TriggerContext)>>;
+
+/// Metadata passed to a trigger callback registered via [`TriggerRegistrationExt::on_trigger`].
+#[derive(Debug, Clone)]
+pub struct TriggerContext {
+    pub animation: String,
+    pub trigger_name: String,
+}
+
+/// One-shot systems registered on an entity via `on_trigger`, keyed by
+/// `(trigger_name, Some(animation))` for animation-scoped bindings or
+/// `(trigger_name, None)` for a binding that fires regardless of which
+/// animation is playing. `bevy_map_runtime`'s dispatch system looks up the
+/// firing trigger here instead of every app writing its own
+/// `MessageReader<AnimationTriggerEvent>` loop.
+#[derive(Component, Default)]
+pub struct AnimationCallbacks {
+    systems: HashMap<(String, Option<String>), TriggerSystemId>,
+}
+
+impl AnimationCallbacks {
+    /// Resolves which system (if any) should run for `trigger_name` firing
+    /// while `animation` plays: the animation-scoped binding wins over one
+    /// registered for any animation.
+    pub fn resolve(&self, trigger_name: &str, animation: &str) -> Option<TriggerSystemId> {
+        let scoped = self.systems.get(&(trigger_name.to_string(), Some(animation.to_string())));
+        let any = self.systems.get(&(trigger_name.to_string(), None));
+        scoped.or(any).copied()
+    }
+}
+
+/// Registers one-shot trigger callbacks directly on `EntityCommands`, e.g.
+/// `commands.entity(sprite).on_trigger("show_blurb", spawn_blurb)`.
+pub trait TriggerRegistrationExt {
+    /// Bind `trigger_name` (fired by any animation) to `system`.
+    fn on_trigger<M>(
+        &mut self,
+        trigger_name: impl Into<String>,
+        system: impl IntoSystem<In<(Entity, TriggerContext)>, (), M> + Send + 'static,
+    ) -> &mut Self;
+
+    /// Bind `trigger_name`, but only while `animation` is playing.
+    fn on_trigger_in<M>(
+        &mut self,
+        animation: impl Into<String>,
+        trigger_name: impl Into<String>,
+        system: impl IntoSystem<In<(Entity, TriggerContext)>, (), M> + Send + 'static,
+    ) -> &mut Self;
+}
+
+impl TriggerRegistrationExt for EntityCommands<'_> {
+    fn on_trigger<M>(
+        &mut self,
+        trigger_name: impl Into<String>,
+        system: impl IntoSystem<In<(Entity, TriggerContext)>, (), M> + Send + 'static,
+    ) -> &mut Self {
+        let trigger_name = trigger_name.into();
+        let entity = self.id();
+        self.commands().queue(move |world: &mut World| {
+            let system_id = world.register_system(system);
+            register_callback(world, entity, trigger_name, None, system_id);
+        });
+        self
+    }
+
+    fn on_trigger_in<M>(
+        &mut self,
+        animation: impl Into<String>,
+        trigger_name: impl Into<String>,
+        system: impl IntoSystem<In<(Entity, TriggerContext)>, (), M> + Send + 'static,
+    ) -> &mut Self {
+        let trigger_name = trigger_name.into();
+        let animation = animation.into();
+        let entity = self.id();
+        self.commands().queue(move |world: &mut World| {
+            let system_id = world.register_system(system);
+            register_callback(world, entity, trigger_name, Some(animation), system_id);
+        });
+        self
+    }
+}
+
+fn register_callback(
+    world: &mut World,
+    entity: Entity,
+    trigger_name: String,
+    animation: Option<String>,
+    system_id: TriggerSystemId,
+) {
+    let mut entity_mut = world.entity_mut(entity);
+    if let Some(mut callbacks) = entity_mut.get_mut::<AnimationCallbacks>() {
+        callbacks.systems.insert((trigger_name, animation), system_id);
+    } else {
+        let mut fresh = AnimationCallbacks::default();
+        fresh.systems.insert((trigger_name, animation), system_id);
+        entity_mut.insert(fresh);
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Shape of a window-driven hitbox or a standing hurtbox. Offset and size
+/// are in world units, relative to the owning entity's world position; the
+/// entity's scale and rotation are not applied to the shape.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum HitboxShape {
+    Rect { offset: Vec2, size: Vec2 },
+    Circle { offset: Vec2, radius: f32 },
+}
+
+impl HitboxShape {
+    pub fn rect(offset: Vec2, size: Vec2) -> Self {
+        Self::Rect { offset, size }
+    }
+
+    pub fn circle(offset: Vec2, radius: f32) -> Self {
+        Self::Circle { offset, radius }
+    }
+
+    /// Axis-aligned bounding box of this shape once placed at `origin`.
+    pub fn aabb(&self, origin: Vec2) -> (Vec2, Vec2) {
+        match *self {
+            Self::Rect { offset, size } => {
+                let center = origin + offset;
+                let half = size * 0.5;
+                (center - half, center + half)
+            }
+            Self::Circle { offset, radius } => {
+                let center = origin + offset;
+                let half = Vec2::splat(radius);
+                (center - half, center + half)
+            }
+        }
+    }
+}
+
+/// Marks an entity as something a window-driven hitbox can hit. Standing
+/// hurtboxes (e.g. a character's body) carry this directly; attack hitboxes
+/// spawned for the duration of a window carry it too, but two attack
+/// hitboxes are never tested against each other.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Hurtbox {
+    pub shape: HitboxShape,
+    pub layer_mask: u32,
+}
+
+impl Hurtbox {
+    pub fn new(shape: HitboxShape, layer_mask: u32) -> Self {
+        Self { shape, layer_mask }
+    }
+
+    pub fn rect(offset: Vec2, size: Vec2) -> Self {
+        Self::new(HitboxShape::rect(offset, size), u32::MAX)
+    }
+
+    pub fn circle(offset: Vec2, radius: f32) -> Self {
+        Self::new(HitboxShape::circle(offset, radius), u32::MAX)
+    }
+
+    pub fn on_layers(mut self, layer_mask: u32) -> Self {
+        self.layer_mask = layer_mask;
+        self
+    }
+}
+
+/// Fired when a window-driven hitbox (the `attacker`) overlaps a [`Hurtbox`]
+/// (the `victim`) whose layer mask it shares. Deduped per window-activation
+/// via [`crate::WindowTracker`].
+#[derive(Message, Debug, Clone)]
+pub struct HitboxCollisionEvent {
+    pub attacker: Entity,
+    pub victim: Entity,
+    pub window_name: String,
+    pub world_point: Vec2,
+}
@@ -0,0 +1,47 @@
+use bevy::input::gamepad::GamepadButton;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::state_machine::ParamValue;
+
+/// A named abstract action, e.g. `"play_tongue"` or `"jump"`, bound to an
+/// [`InputSource`] via a [`BindingDef`] or `bevy_map_runtime::InputBindings`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InputAction(pub String);
+
+impl InputAction {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+}
+
+/// A physical input that can drive an [`InputAction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputSource {
+    Key(KeyCode),
+    MouseButton(MouseButton),
+    GamepadButton(GamepadButton),
+}
+
+/// What happens to the targeted `AnimatedSprite`/[`crate::AnimationStateMachine`]
+/// when a binding's action fires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum InputEffect {
+    PlayAnimation(String),
+    StopAnimation,
+    SetStateMachineParam(String, ParamValue),
+}
+
+/// One [`InputAction`] binding, as authored in `.map.json`. Mirrors
+/// `bevy_map_runtime::InputBindings::bind`/`bind_held`'s parameters so it can
+/// be deserialized directly and loaded with `InputBindings::load`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BindingDef {
+    pub action: InputAction,
+    pub source: InputSource,
+    /// Fires on every frame `source` is held down, instead of only the frame
+    /// it's first pressed.
+    #[serde(default)]
+    pub held: bool,
+    pub effect: InputEffect,
+}
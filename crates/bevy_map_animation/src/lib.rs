@@ -0,0 +1,24 @@
+//! Core data types for `bevy_map_editor` animations: sprite playback, the
+//! trigger/window timeline, hitboxes, audio cues, state machines and
+//! per-trigger callbacks. `bevy_map_runtime` turns these into running
+//! systems; this crate only owns the data and the small amount of logic
+//! that runs outside those systems (timeline ticking, state machine
+//! transition evaluation, hitbox AABBs, callback registration).
+
+mod audio;
+mod callbacks;
+mod hitbox;
+mod input;
+mod project;
+mod sprite;
+mod state_machine;
+mod trigger;
+
+pub use audio::AudioTrigger;
+pub use callbacks::{AnimationCallbacks, TriggerContext, TriggerRegistrationExt};
+pub use hitbox::{HitboxCollisionEvent, HitboxShape, Hurtbox};
+pub use input::{BindingDef, InputAction, InputEffect, InputSource};
+pub use project::{AnimationDef, MapProject, MapProjectLoader, SpriteSheetDef};
+pub use sprite::{AnimatedSprite, TickOutcome};
+pub use state_machine::{AnimationStateMachine, AnimationStateMachineDef, ParamValue, StateDef, TransitionCondition, TransitionDef};
+pub use trigger::{AnimationTriggerEvent, AnimationWindowEvent, TriggerDef, WindowDef, WindowPhase, WindowTracker};
@@ -0,0 +1,91 @@
+use bevy::asset::{io::Reader, Asset, AssetLoader, LoadContext};
+use bevy::reflect::TypePath;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::input::BindingDef;
+use crate::state_machine::AnimationStateMachineDef;
+use crate::trigger::{TriggerDef, WindowDef};
+
+/// A single named animation on a sprite sheet: how long it runs, whether it
+/// loops, and the triggers/windows on its timeline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationDef {
+    pub name: String,
+    pub duration_ms: f32,
+    #[serde(default)]
+    pub looping: bool,
+    #[serde(default)]
+    pub triggers: Vec<TriggerDef>,
+    #[serde(default)]
+    pub windows: Vec<WindowDef>,
+}
+
+/// One sprite sheet's worth of animations, as authored in `.map.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpriteSheetDef {
+    pub name: String,
+    pub texture: String,
+    #[serde(default)]
+    pub animations: Vec<AnimationDef>,
+    /// Optional state machine driving which animation plays, as an
+    /// alternative to calling `AnimatedSprite::play` imperatively.
+    #[serde(default)]
+    pub state_machine: Option<AnimationStateMachineDef>,
+}
+
+impl SpriteSheetDef {
+    pub fn animation(&self, name: &str) -> Option<&AnimationDef> {
+        self.animations.iter().find(|a| a.name == name)
+    }
+}
+
+/// A loaded `.map.json` project: every sprite sheet and its animations.
+#[derive(Asset, TypePath, Debug, Clone, Serialize, Deserialize)]
+pub struct MapProject {
+    #[serde(default)]
+    pub sprite_sheets: Vec<SpriteSheetDef>,
+    /// Input bindings shared across the whole project, as an alternative to
+    /// building `bevy_map_runtime::InputBindings` in code. Load them with
+    /// `InputBindings::load`.
+    #[serde(default)]
+    pub input_bindings: Vec<BindingDef>,
+}
+
+impl MapProject {
+    pub fn sprite_sheet(&self, name: &str) -> Option<&SpriteSheetDef> {
+        self.sprite_sheets.iter().find(|s| s.name == name)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum MapProjectLoaderError {
+    #[error("failed to read map project file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to parse map project JSON: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+#[derive(Default, TypePath)]
+pub struct MapProjectLoader;
+
+impl AssetLoader for MapProjectLoader {
+    type Asset = MapProject;
+    type Settings = ();
+    type Error = MapProjectLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["map.json"]
+    }
+}
@@ -0,0 +1,145 @@
+use bevy::prelude::*;
+
+use crate::project::AnimationDef;
+use crate::trigger::{TriggerDef, WindowPhase};
+
+/// Drives playback of one of the animations loaded from a `.map.json` sprite
+/// sheet. `bevy_map_runtime` calls [`AnimatedSprite::tick`] each frame and
+/// turns its [`TickOutcome`] into [`crate::AnimationTriggerEvent`]/
+/// [`crate::AnimationWindowEvent`]/[`crate::HitboxCollisionEvent`]s.
+#[derive(Component, Debug, Default)]
+pub struct AnimatedSprite {
+    pub current_animation: Option<String>,
+    pub playing: bool,
+    /// Milliseconds into `current_animation` since it started playing.
+    pub elapsed_ms: f32,
+    prev_elapsed_ms: f32,
+}
+
+/// What happened during one [`AnimatedSprite::tick`] call.
+#[derive(Debug, Default)]
+pub struct TickOutcome {
+    pub fired_triggers: Vec<TriggerDef>,
+    pub window_transitions: Vec<(String, WindowPhase)>,
+    pub completed: bool,
+}
+
+impl AnimatedSprite {
+    pub fn play(&mut self, animation: impl Into<String>) {
+        self.current_animation = Some(animation.into());
+        self.elapsed_ms = 0.0;
+        self.prev_elapsed_ms = 0.0;
+        self.playing = true;
+    }
+
+    pub fn stop(&mut self) {
+        self.playing = false;
+    }
+
+    /// Advances playback by `dt_ms` against `anim`'s timeline, reporting any
+    /// triggers crossed and window phases entered/ticked/exited - including
+    /// across a loop wrap, so a trigger at the very end of a looping
+    /// animation still fires.
+    pub fn tick(&mut self, dt_ms: f32, anim: &AnimationDef) -> TickOutcome {
+        let mut outcome = TickOutcome::default();
+        if !self.playing || dt_ms <= 0.0 {
+            return outcome;
+        }
+
+        self.prev_elapsed_ms = self.elapsed_ms;
+        let mut new_elapsed = self.elapsed_ms + dt_ms;
+        let wrapped = anim.looping && new_elapsed >= anim.duration_ms;
+        if wrapped {
+            new_elapsed %= anim.duration_ms.max(1.0);
+        } else if new_elapsed >= anim.duration_ms {
+            new_elapsed = anim.duration_ms;
+        }
+
+        for trigger in &anim.triggers {
+            let crossed = if wrapped {
+                trigger.time_ms > self.prev_elapsed_ms || trigger.time_ms <= new_elapsed
+            } else {
+                trigger.time_ms > self.prev_elapsed_ms && trigger.time_ms <= new_elapsed
+            };
+            if crossed {
+                outcome.fired_triggers.push(trigger.clone());
+            }
+        }
+
+        for window in &anim.windows {
+            let was_open = Self::within(self.prev_elapsed_ms, window.start_ms, window.end_ms, wrapped, anim.duration_ms);
+            let is_open = Self::within(new_elapsed, window.start_ms, window.end_ms, false, anim.duration_ms);
+            if is_open && !was_open {
+                outcome.window_transitions.push((window.name.clone(), WindowPhase::Begin));
+            } else if is_open {
+                outcome.window_transitions.push((window.name.clone(), WindowPhase::Tick));
+            } else if was_open {
+                outcome.window_transitions.push((window.name.clone(), WindowPhase::End));
+            }
+        }
+
+        self.elapsed_ms = new_elapsed;
+
+        if !anim.looping && new_elapsed >= anim.duration_ms {
+            self.playing = false;
+            outcome.completed = true;
+        }
+
+        outcome
+    }
+
+    fn within(elapsed_ms: f32, start_ms: f32, end_ms: f32, wrapped_hint: bool, duration_ms: f32) -> bool {
+        if wrapped_hint && elapsed_ms > duration_ms {
+            return false;
+        }
+        elapsed_ms >= start_ms && elapsed_ms < end_ms
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::AnimationDef;
+    use crate::trigger::TriggerDef;
+
+    fn looping_anim(duration_ms: f32, trigger_times_ms: &[f32]) -> AnimationDef {
+        AnimationDef {
+            name: "loop".to_string(),
+            duration_ms,
+            looping: true,
+            triggers: trigger_times_ms
+                .iter()
+                .map(|&time_ms| TriggerDef {
+                    name: format!("t{time_ms}"),
+                    time_ms,
+                    audio: None,
+                })
+                .collect(),
+            windows: Vec::new(),
+        }
+    }
+
+    /// A trigger right at the end of a looping animation's timeline must
+    /// still fire when a single tick's `dt` carries playback past the loop
+    /// point, and a trigger right after the restart point re-arms and fires
+    /// again in that same wrapped tick.
+    #[test]
+    fn triggers_fire_across_a_loop_wrap() {
+        let anim = looping_anim(100.0, &[95.0, 3.0]);
+        let mut sprite = AnimatedSprite::default();
+        sprite.play("loop");
+
+        let outcome = sprite.tick(90.0, &anim);
+        let fired: Vec<_> = outcome.fired_triggers.iter().map(|t| t.time_ms).collect();
+        assert_eq!(fired, vec![3.0], "only the early trigger is in [0, 90)");
+
+        let outcome = sprite.tick(20.0, &anim);
+        assert!(sprite.playing, "looping animations never stop");
+        let fired: Vec<_> = outcome.fired_triggers.iter().map(|t| t.time_ms).collect();
+        assert_eq!(
+            fired,
+            vec![95.0, 3.0],
+            "the tail trigger fires via the pre-wrap elapsed time, and the head trigger re-arms for the next loop"
+        );
+    }
+}
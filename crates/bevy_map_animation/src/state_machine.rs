@@ -0,0 +1,263 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Value stored for a named parameter on an [`AnimationStateMachine`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ParamValue {
+    Bool(bool),
+    Int(i32),
+    Float(f32),
+    String(String),
+}
+
+/// A state in the machine, mapping to one of the animations loaded from the
+/// sprite's map project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateDef {
+    pub name: String,
+    pub animation: String,
+}
+
+impl StateDef {
+    pub fn new(name: impl Into<String>, animation: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            animation: animation.into(),
+        }
+    }
+}
+
+/// What causes a transition to fire.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TransitionCondition {
+    /// Fires when a trigger with this name is fired via `fire()` or an
+    /// `AnimationTriggerEvent` for the current animation.
+    Trigger(String),
+    /// Fires once the current state's animation finishes playing.
+    OnComplete,
+    /// Fires while `params[name] == value`.
+    Param(String, ParamValue),
+}
+
+impl TransitionCondition {
+    fn matches(&self, fired_triggers: &[String], completed: bool, params: &HashMap<String, ParamValue>) -> bool {
+        match self {
+            Self::Trigger(name) => fired_triggers.iter().any(|t| t == name),
+            Self::OnComplete => completed,
+            Self::Param(name, value) => params.get(name) == Some(value),
+        }
+    }
+}
+
+/// One outgoing edge: move to `target` when `condition` is satisfied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitionDef {
+    pub target: String,
+    pub condition: TransitionCondition,
+}
+
+impl TransitionDef {
+    pub fn new(target: impl Into<String>, condition: TransitionCondition) -> Self {
+        Self {
+            target: target.into(),
+            condition,
+        }
+    }
+}
+
+/// Describes animation behavior data-side: states, transitions, and the
+/// triggers/params that drive them, instead of imperative `play()`/`stop()`
+/// calls scattered across `handle_input`-style systems.
+///
+/// `bevy_map_runtime`'s driving system evaluates `any_state` transitions
+/// first (so e.g. a `hit` reaction can interrupt anything), then the current
+/// state's own transitions in priority order, and calls the existing
+/// `AnimatedSprite::play()` on the first satisfied condition.
+#[derive(Component, Debug)]
+pub struct AnimationStateMachine {
+    current: String,
+    states: HashMap<String, StateDef>,
+    transitions: HashMap<String, Vec<TransitionDef>>,
+    any_state: Vec<TransitionDef>,
+    params: HashMap<String, ParamValue>,
+    pending_triggers: Vec<String>,
+}
+
+impl AnimationStateMachine {
+    pub fn new(initial_state: impl Into<String>) -> Self {
+        Self {
+            current: initial_state.into(),
+            states: HashMap::new(),
+            transitions: HashMap::new(),
+            any_state: Vec::new(),
+            params: HashMap::new(),
+            pending_triggers: Vec::new(),
+        }
+    }
+
+    pub fn add_state(&mut self, state: StateDef) -> &mut Self {
+        self.states.insert(state.name.clone(), state);
+        self
+    }
+
+    pub fn add_transition(&mut self, from_state: impl Into<String>, transition: TransitionDef) -> &mut Self {
+        self.transitions.entry(from_state.into()).or_default().push(transition);
+        self
+    }
+
+    pub fn add_any_state_transition(&mut self, transition: TransitionDef) -> &mut Self {
+        self.any_state.push(transition);
+        self
+    }
+
+    pub fn set_param(&mut self, name: impl Into<String>, value: ParamValue) {
+        self.params.insert(name.into(), value);
+    }
+
+    pub fn param(&self, name: &str) -> Option<&ParamValue> {
+        self.params.get(name)
+    }
+
+    /// Queue a named trigger for evaluation on the next `advance()` call.
+    pub fn fire(&mut self, trigger_name: impl Into<String>) {
+        self.pending_triggers.push(trigger_name.into());
+    }
+
+    pub fn current_state(&self) -> &str {
+        &self.current
+    }
+
+    /// Evaluates `any_state` transitions, then the current state's own, in
+    /// priority order. Returns the animation to play if a transition fired.
+    /// Called once per frame by `bevy_map_runtime`'s driving system. Clears
+    /// the queued triggers whether or not a transition fired.
+    pub fn advance(&mut self, completed: bool) -> Option<String> {
+        let target = Self::first_match(&self.any_state, &self.pending_triggers, completed, &self.params)
+            .or_else(|| {
+                let outgoing = self.transitions.get(&self.current)?;
+                Self::first_match(outgoing, &self.pending_triggers, completed, &self.params)
+            });
+
+        self.pending_triggers.clear();
+
+        let target = target?;
+        self.current = target.clone();
+        self.states.get(&target).map(|state| state.animation.clone())
+    }
+
+    fn first_match(
+        transitions: &[TransitionDef],
+        fired_triggers: &[String],
+        completed: bool,
+        params: &HashMap<String, ParamValue>,
+    ) -> Option<String> {
+        transitions
+            .iter()
+            .find(|t| t.condition.matches(fired_triggers, completed, params))
+            .map(|t| t.target.clone())
+    }
+}
+
+/// A full [`AnimationStateMachine`] definition, as authored in `.map.json`.
+/// Mirrors the builder API field-for-field so it can be deserialized
+/// directly and turned into a live component via [`Self::build`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationStateMachineDef {
+    pub initial_state: String,
+    #[serde(default)]
+    pub states: Vec<StateDef>,
+    /// Keyed by the state name transitions are evaluated from.
+    #[serde(default)]
+    pub transitions: HashMap<String, Vec<TransitionDef>>,
+    #[serde(default)]
+    pub any_state: Vec<TransitionDef>,
+}
+
+impl AnimationStateMachineDef {
+    /// Materializes a live [`AnimationStateMachine`] component from this
+    /// definition.
+    pub fn build(&self) -> AnimationStateMachine {
+        let mut machine = AnimationStateMachine::new(self.initial_state.clone());
+        for state in &self.states {
+            machine.add_state(state.clone());
+        }
+        for (from_state, transitions) in &self.transitions {
+            for transition in transitions {
+                machine.add_transition(from_state.clone(), transition.clone());
+            }
+        }
+        for transition in &self.any_state {
+            machine.add_any_state_transition(transition.clone());
+        }
+        machine
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn machine_with(any_state: Vec<TransitionDef>, from_idle: Vec<TransitionDef>) -> AnimationStateMachine {
+        let mut machine = AnimationStateMachine::new("idle");
+        machine.add_state(StateDef::new("idle", "idle_anim"));
+        machine.add_state(StateDef::new("hit", "hit_anim"));
+        machine.add_state(StateDef::new("run", "run_anim"));
+        for transition in any_state {
+            machine.add_any_state_transition(transition);
+        }
+        for transition in from_idle {
+            machine.add_transition("idle", transition);
+        }
+        machine
+    }
+
+    /// `any_state` transitions must be checked before the current state's
+    /// own, so e.g. a `hit` reaction can interrupt any state.
+    #[test]
+    fn any_state_transitions_take_priority_over_current_state_transitions() {
+        let mut machine = machine_with(
+            vec![TransitionDef::new("hit", TransitionCondition::Trigger("hit".to_string()))],
+            vec![TransitionDef::new("run", TransitionCondition::Trigger("hit".to_string()))],
+        );
+
+        machine.fire("hit");
+        assert_eq!(machine.advance(false), Some("hit_anim".to_string()));
+        assert_eq!(machine.current_state(), "hit");
+    }
+
+    /// Within a state's own transitions, the first one whose condition
+    /// matches wins, regardless of how many later ones would also match.
+    #[test]
+    fn first_matching_transition_wins_within_a_state() {
+        let mut machine = machine_with(
+            Vec::new(),
+            vec![
+                TransitionDef::new("run", TransitionCondition::Trigger("go".to_string())),
+                TransitionDef::new("hit", TransitionCondition::Trigger("go".to_string())),
+            ],
+        );
+
+        machine.fire("go");
+        assert_eq!(machine.advance(false), Some("run_anim".to_string()));
+        assert_eq!(machine.current_state(), "run");
+    }
+
+    #[test]
+    fn def_build_round_trips_into_a_working_machine() {
+        let def = AnimationStateMachineDef {
+            initial_state: "idle".to_string(),
+            states: vec![StateDef::new("idle", "idle_anim"), StateDef::new("run", "run_anim")],
+            transitions: HashMap::from([(
+                "idle".to_string(),
+                vec![TransitionDef::new("run", TransitionCondition::Trigger("go".to_string()))],
+            )]),
+            any_state: Vec::new(),
+        };
+
+        let mut machine = def.build();
+        machine.fire("go");
+        assert_eq!(machine.advance(false), Some("run_anim".to_string()));
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::audio::AudioTrigger;
+use crate::hitbox::HitboxShape;
+
+/// A named, instantaneous point on an animation's timeline, as authored in
+/// `.map.json`. Carries an optional [`AudioTrigger`] so a sound can play the
+/// moment the trigger fires, with zero per-app code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerDef {
+    pub name: String,
+    pub time_ms: f32,
+    #[serde(default)]
+    pub audio: Option<AudioTrigger>,
+}
+
+/// A named time range on an animation's timeline, as authored in
+/// `.map.json`. Carries an optional hitbox shape + layer mask so the window
+/// can drive a real collider instead of just reporting `Begin`/`Tick`/`End`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WindowDef {
+    pub name: String,
+    pub start_ms: f32,
+    pub end_ms: f32,
+    #[serde(default)]
+    pub hitbox: Option<HitboxShape>,
+    #[serde(default)]
+    pub layer_mask: u32,
+}
+
+/// Fired once when a [`TriggerDef`]'s `time_ms` is crossed during playback.
+#[derive(Message, Debug, Clone)]
+pub struct AnimationTriggerEvent {
+    pub entity: Entity,
+    pub animation: String,
+    pub trigger_name: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowPhase {
+    Begin,
+    Tick,
+    End,
+}
+
+/// Fired as playback enters, stays within, and leaves a [`WindowDef`]'s
+/// `start_ms..end_ms` range.
+#[derive(Message, Debug, Clone)]
+pub struct AnimationWindowEvent {
+    pub entity: Entity,
+    pub animation: String,
+    pub window_name: String,
+    pub phase: WindowPhase,
+}
+
+/// Required on any entity that wants window events (and window-driven
+/// hitboxes). Tracks which windows are currently open, and - per
+/// window-activation - which hurtboxes have already been hit, so a single
+/// swing only registers one [`crate::HitboxCollisionEvent`] per victim.
+#[derive(Component, Debug, Default)]
+pub struct WindowTracker {
+    open: HashSet<String>,
+    hit_this_activation: HashMap<String, HashSet<Entity>>,
+}
+
+impl WindowTracker {
+    pub fn is_open(&self, window_name: &str) -> bool {
+        self.open.contains(window_name)
+    }
+
+    /// Starts a new activation of `window_name`, forgetting hits recorded by
+    /// an earlier one that never got an `End` (e.g. the animation restarted).
+    pub fn open_window(&mut self, window_name: impl Into<String>) {
+        let window_name = window_name.into();
+        self.clear_hits(&window_name);
+        self.open.insert(window_name);
+    }
+
+    pub fn close_window(&mut self, window_name: &str) {
+        self.open.remove(window_name);
+        self.clear_hits(window_name);
+    }
+
+    /// True the first time `victim` is seen for the current activation of
+    /// `window_name`; records it so later calls this activation return false.
+    pub fn record_hit(&mut self, window_name: &str, victim: Entity) -> bool {
+        self.hit_this_activation
+            .entry(window_name.to_string())
+            .or_default()
+            .insert(victim)
+    }
+
+    pub fn clear_hits(&mut self, window_name: &str) {
+        self.hit_this_activation.remove(window_name);
+    }
+}
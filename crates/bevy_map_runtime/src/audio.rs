@@ -0,0 +1,40 @@
+use bevy::audio::{AudioPlayer, PlaybackSettings, Volume};
+use bevy::prelude::*;
+use bevy_map_animation::AudioTrigger;
+
+use crate::playback::FrameOutcome;
+
+/// Spawns an `AudioPlayer`/`AudioSource` for every trigger that fired this
+/// frame and carries an [`AudioTrigger`], so named triggers play SFX with
+/// zero per-app code. Spatial triggers are spawned as a child of the firing
+/// entity so their 2D positional falloff (relative to a
+/// [`bevy::audio::SpatialListener`] elsewhere in the scene) follows its
+/// `Transform`; non-spatial ones just play at a fixed volume.
+pub(crate) fn spawn_audio_on_triggers(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut frames: MessageReader<FrameOutcome>,
+) {
+    for frame in frames.read() {
+        for trigger in &frame.fired_triggers {
+            let Some(audio) = &trigger.audio else {
+                continue;
+            };
+            spawn_audio_trigger(&mut commands, &asset_server, frame.entity, audio);
+        }
+    }
+}
+
+fn spawn_audio_trigger(commands: &mut Commands, asset_server: &AssetServer, owner: Entity, audio: &AudioTrigger) {
+    let player = AudioPlayer::new(asset_server.load(&audio.asset));
+    let settings = PlaybackSettings::DESPAWN
+        .with_volume(Volume::Linear(audio.volume))
+        .with_speed(audio.pitch)
+        .with_spatial(audio.spatial);
+
+    if audio.spatial {
+        commands.spawn((player, settings, Transform::default(), ChildOf(owner)));
+    } else {
+        commands.spawn((player, settings));
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use bevy_map_animation::{AnimationCallbacks, AnimationTriggerEvent, TriggerContext};
+
+/// Looks up each firing trigger in the entity's [`AnimationCallbacks`] - the
+/// animation-scoped binding takes priority over the any-animation one - and
+/// queues the matching one-shot system, replacing the 10-line
+/// `MessageReader<AnimationTriggerEvent>` match loop every consumer used to
+/// write by hand.
+pub(crate) fn dispatch_trigger_callbacks(
+    mut commands: Commands,
+    mut triggers: MessageReader<AnimationTriggerEvent>,
+    callbacks: Query<&AnimationCallbacks>,
+) {
+    for event in triggers.read() {
+        let Ok(callbacks) = callbacks.get(event.entity) else {
+            continue;
+        };
+
+        if let Some(system_id) = callbacks.resolve(&event.trigger_name, &event.animation) {
+            let ctx = TriggerContext {
+                animation: event.animation.clone(),
+                trigger_name: event.trigger_name.clone(),
+            };
+            commands.run_system_with(system_id, (event.entity, ctx));
+        }
+    }
+}
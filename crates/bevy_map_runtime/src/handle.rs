@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use bevy_map_animation::MapProject;
+
+/// Spawned alongside a `Transform` to load an animated sprite from a map
+/// project in one line. `MapRuntimePlugin`'s `resolve_sprite_handles` system
+/// waits for `project` to load, finds `sheet_name` on it, loads its texture,
+/// and adds an `AnimatedSprite` + `Sprite` already playing `start_animation`.
+/// The handle stays on the entity: playback looks up the timeline through it.
+#[derive(Component, Debug, Clone)]
+pub struct AnimatedSpriteHandle {
+    pub project: Handle<MapProject>,
+    pub sheet_name: String,
+    pub start_animation: String,
+    pub scale: f32,
+}
+
+impl AnimatedSpriteHandle {
+    pub fn new(
+        project: Handle<MapProject>,
+        sheet_name: impl Into<String>,
+        start_animation: impl Into<String>,
+    ) -> Self {
+        Self {
+            project,
+            sheet_name: sheet_name.into(),
+            start_animation: start_animation.into(),
+            scale: 1.0,
+        }
+    }
+
+    pub fn with_scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_map_animation::{HitboxCollisionEvent, Hurtbox, WindowPhase, WindowTracker};
+
+use crate::playback::FrameOutcome;
+
+/// Tracks, per owner entity, which child collider was spawned for which
+/// open window, so `spawn_and_despawn_hitboxes` can find it again on `End`.
+#[derive(Component, Default)]
+pub(crate) struct SpawnedWindowHitboxes {
+    colliders: HashMap<String, Entity>,
+}
+
+/// Marks a collider entity spawned for the duration of a window as the
+/// attacking side of an overlap test, and records who owns it.
+#[derive(Component)]
+pub(crate) struct ActiveHitbox {
+    pub owner: Entity,
+    pub window_name: String,
+}
+
+pub(crate) fn spawn_and_despawn_hitboxes(
+    mut commands: Commands,
+    mut frames: MessageReader<FrameOutcome>,
+    mut owners: Query<Option<&mut SpawnedWindowHitboxes>>,
+) {
+    for frame in frames.read() {
+        for transition in &frame.window_transitions {
+            match transition.phase {
+                WindowPhase::Begin => {
+                    let Some(shape) = transition.hitbox else {
+                        continue;
+                    };
+                    let collider = commands
+                        .spawn((
+                            Hurtbox::new(shape, transition.layer_mask),
+                            ActiveHitbox {
+                                owner: frame.entity,
+                                window_name: transition.name.clone(),
+                            },
+                            Transform::default(),
+                            ChildOf(frame.entity),
+                        ))
+                        .id();
+
+                    match owners.get_mut(frame.entity) {
+                        Ok(Some(mut spawned)) => {
+                            // A window restarted without an `End` still has its old collider.
+                            if let Some(stale) = spawned.colliders.insert(transition.name.clone(), collider) {
+                                commands.entity(stale).despawn();
+                            }
+                        }
+                        _ => {
+                            let mut spawned = SpawnedWindowHitboxes::default();
+                            spawned.colliders.insert(transition.name.clone(), collider);
+                            commands.entity(frame.entity).insert(spawned);
+                        }
+                    }
+                }
+                WindowPhase::End => {
+                    if let Ok(Some(mut spawned)) = owners.get_mut(frame.entity)
+                        && let Some(collider) = spawned.colliders.remove(&transition.name)
+                    {
+                        commands.entity(collider).despawn();
+                    }
+                }
+                WindowPhase::Tick => {}
+            }
+        }
+    }
+}
+
+/// A simple O(n log n) broad-phase sweep: sort every live hitbox/hurtbox by
+/// its world-space AABB min-x, then only test neighbors whose ranges
+/// overlap on the x axis. Attacking hitboxes ([`ActiveHitbox`]) are tested
+/// against standing [`Hurtbox`]-only entities on a shared layer; repeat
+/// hits within the same window-activation are deduped via [`WindowTracker`].
+///
+/// Each pair is tested once regardless of which side sorts first: an
+/// attacker/hurtbox pair is found whether the attacker's AABB min-x comes
+/// before or after the hurtbox's.
+pub(crate) fn sweep_hitbox_overlaps(
+    mut collisions: MessageWriter<HitboxCollisionEvent>,
+    hitboxes: Query<(Entity, &GlobalTransform, &Hurtbox, Option<&ActiveHitbox>)>,
+    mut trackers: Query<&mut WindowTracker>,
+) {
+    let mut entries: Vec<_> = hitboxes
+        .iter()
+        .map(|(entity, transform, hurtbox, attacker)| {
+            let origin = transform.translation().truncate();
+            let (min, max) = hurtbox.shape.aabb(origin);
+            (entity, min, max, hurtbox.layer_mask, attacker)
+        })
+        .collect();
+    entries.sort_by(|a, b| a.1.x.partial_cmp(&b.1.x).unwrap_or(std::cmp::Ordering::Equal));
+
+    for i in 0..entries.len() {
+        let (entity_i, i_min, i_max, i_mask, i_active) = entries[i];
+
+        for other in entries.iter().skip(i + 1) {
+            let (entity_j, j_min, j_max, j_mask, j_active) = *other;
+            if j_min.x > i_max.x {
+                break; // sorted by min-x: nothing further can overlap on x
+            }
+            if i_mask & j_mask == 0 {
+                continue;
+            }
+
+            // Whichever side carries `ActiveHitbox` is the attacker, the
+            // other the victim; skip if neither or both sides are active, or
+            // if the active side's collider is hitting its own owner.
+            let attack = match (i_active, j_active) {
+                (Some(active), None) if entity_j != active.owner => Some((active, entity_j)),
+                (None, Some(active)) if entity_i != active.owner => Some((active, entity_i)),
+                _ => None,
+            };
+            let Some((active, victim_entity)) = attack else {
+                continue;
+            };
+
+            let overlapping = i_min.x < j_max.x && i_max.x > j_min.x && i_min.y < j_max.y && i_max.y > j_min.y;
+            if !overlapping {
+                continue;
+            }
+
+            let already_hit = trackers
+                .get_mut(active.owner)
+                .map(|mut tracker| !tracker.record_hit(&active.window_name, victim_entity))
+                .unwrap_or(false);
+            if already_hit {
+                continue;
+            }
+
+            let world_point = Vec2::new(i_min.x.max(j_min.x).min(i_max.x.min(j_max.x)), i_min.y.max(j_min.y).min(i_max.y.min(j_max.y)));
+            collisions.write(HitboxCollisionEvent {
+                attacker: active.owner,
+                victim: victim_entity,
+                window_name: active.window_name.clone(),
+                world_point,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn run_sweep(world: &mut World) -> Vec<HitboxCollisionEvent> {
+        world.run_system_once(sweep_hitbox_overlaps).unwrap();
+        world.resource_mut::<Messages<HitboxCollisionEvent>>().drain().collect()
+    }
+
+    /// Regression test for a bug where the sweep only found a pair when the
+    /// attacker's AABB min-x sorted before the victim's; swapping their
+    /// positions made the pair invisible in either direction.
+    #[test]
+    fn finds_overlap_regardless_of_aabb_sort_order() {
+        for swap in [false, true] {
+            let mut world = World::new();
+            world.init_resource::<Messages<HitboxCollisionEvent>>();
+
+            let owner = world.spawn(WindowTracker::default()).id();
+            let attacker_x = if swap { 10.0 } else { 0.0 };
+            let victim_x = if swap { 0.0 } else { 10.0 };
+
+            world.spawn((
+                Hurtbox::rect(Vec2::ZERO, Vec2::splat(20.0)),
+                ActiveHitbox {
+                    owner,
+                    window_name: "hit".to_string(),
+                },
+                GlobalTransform::from(Transform::from_xyz(attacker_x, 0.0, 0.0)),
+            ));
+            let victim = world
+                .spawn((
+                    Hurtbox::rect(Vec2::ZERO, Vec2::splat(20.0)),
+                    GlobalTransform::from(Transform::from_xyz(victim_x, 0.0, 0.0)),
+                ))
+                .id();
+
+            let events = run_sweep(&mut world);
+            assert_eq!(events.len(), 1, "swap={swap}");
+            assert_eq!(events[0].attacker, owner);
+            assert_eq!(events[0].victim, victim);
+        }
+    }
+
+    #[test]
+    fn two_attackers_never_hit_each_other() {
+        let mut world = World::new();
+        world.init_resource::<Messages<HitboxCollisionEvent>>();
+
+        let owner_a = world.spawn(WindowTracker::default()).id();
+        let owner_b = world.spawn(WindowTracker::default()).id();
+        world.spawn((
+            Hurtbox::rect(Vec2::ZERO, Vec2::splat(20.0)),
+            ActiveHitbox {
+                owner: owner_a,
+                window_name: "hit".to_string(),
+            },
+            GlobalTransform::from(Transform::from_xyz(0.0, 0.0, 0.0)),
+        ));
+        world.spawn((
+            Hurtbox::rect(Vec2::ZERO, Vec2::splat(20.0)),
+            ActiveHitbox {
+                owner: owner_b,
+                window_name: "hit".to_string(),
+            },
+            GlobalTransform::from(Transform::from_xyz(5.0, 0.0, 0.0)),
+        ));
+
+        assert!(run_sweep(&mut world).is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+
+use bevy::input::gamepad::Gamepad;
+use bevy::prelude::*;
+pub use bevy_map_animation::{InputAction, InputEffect, InputSource};
+use bevy_map_animation::{AnimatedSprite, AnimationStateMachine, BindingDef};
+
+#[derive(Debug, Clone)]
+struct Binding {
+    source: InputSource,
+    held: bool,
+    effect: InputEffect,
+}
+
+/// Maps [`InputAction`]s to [`InputSource`]s and the [`InputEffect`] to apply
+/// when they fire, instead of hardcoded `KeyCode` matching scattered across
+/// `handle_input`-style systems. Resolved once per frame by
+/// [`resolve_input_bindings`] against every entity with an [`AnimatedSprite`].
+#[derive(Resource, Default)]
+pub struct InputBindings {
+    bindings: HashMap<String, Binding>,
+    fired_this_frame: HashMap<String, bool>,
+}
+
+impl InputBindings {
+    /// Binds `action` to fire on the frame `source` is first pressed.
+    pub fn bind(&mut self, action: InputAction, source: InputSource, effect: InputEffect) {
+        self.bindings.insert(
+            action.0,
+            Binding {
+                source,
+                held: false,
+                effect,
+            },
+        );
+    }
+
+    /// Binds `action` to fire on every frame `source` is held down.
+    pub fn bind_held(&mut self, action: InputAction, source: InputSource, effect: InputEffect) {
+        self.bindings.insert(
+            action.0,
+            Binding {
+                source,
+                held: true,
+                effect,
+            },
+        );
+    }
+
+    /// Whether `action`'s binding fired this frame.
+    pub fn just_fired(&self, action: &str) -> bool {
+        self.fired_this_frame.get(action).copied().unwrap_or(false)
+    }
+
+    /// Binds every [`BindingDef`] in `defs`, e.g. a loaded
+    /// `bevy_map_animation::MapProject::input_bindings` - as an alternative
+    /// to calling `bind`/`bind_held` in code.
+    pub fn load(&mut self, defs: &[BindingDef]) {
+        for def in defs {
+            if def.held {
+                self.bind_held(def.action.clone(), def.source, def.effect.clone());
+            } else {
+                self.bind(def.action.clone(), def.source, def.effect.clone());
+            }
+        }
+    }
+}
+
+fn is_active(
+    source: InputSource,
+    held: bool,
+    keys: &ButtonInput<KeyCode>,
+    mouse: &ButtonInput<MouseButton>,
+    gamepads: &Query<&Gamepad>,
+) -> bool {
+    match source {
+        InputSource::Key(key) => {
+            if held {
+                keys.pressed(key)
+            } else {
+                keys.just_pressed(key)
+            }
+        }
+        InputSource::MouseButton(button) => {
+            if held {
+                mouse.pressed(button)
+            } else {
+                mouse.just_pressed(button)
+            }
+        }
+        InputSource::GamepadButton(button) => gamepads.iter().any(|gamepad| {
+            if held {
+                gamepad.pressed(button)
+            } else {
+                gamepad.just_pressed(button)
+            }
+        }),
+    }
+}
+
+fn apply_effect(effect: &InputEffect, sprite: &mut AnimatedSprite, machine: Option<&mut AnimationStateMachine>) {
+    match effect {
+        InputEffect::PlayAnimation(animation) => sprite.play(animation.clone()),
+        InputEffect::StopAnimation => sprite.stop(),
+        InputEffect::SetStateMachineParam(name, value) => {
+            if let Some(machine) = machine {
+                machine.set_param(name.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Resolves every [`InputBindings`] entry against this frame's input state
+/// and, for any that fired, applies its [`InputEffect`] to every
+/// [`AnimatedSprite`] (and [`AnimationStateMachine`], if present).
+pub(crate) fn resolve_input_bindings(
+    mut bindings: ResMut<InputBindings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    mut sprites: Query<(&mut AnimatedSprite, Option<&mut AnimationStateMachine>)>,
+) {
+    let fired: Vec<(String, InputEffect)> = bindings
+        .bindings
+        .iter()
+        .filter_map(|(name, binding)| {
+            if is_active(binding.source, binding.held, &keys, &mouse, &gamepads) {
+                Some((name.clone(), binding.effect.clone()))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    bindings.fired_this_frame.clear();
+    for (name, _) in &fired {
+        bindings.fired_this_frame.insert(name.clone(), true);
+    }
+
+    for (mut sprite, mut machine) in &mut sprites {
+        for (_, effect) in &fired {
+            apply_effect(effect, &mut sprite, machine.as_deref_mut());
+        }
+    }
+}
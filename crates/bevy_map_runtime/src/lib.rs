@@ -0,0 +1,17 @@
+//! Turns `bevy_map_animation`'s data (sprite timelines, triggers, windows)
+//! into running Bevy systems via [`MapRuntimePlugin`].
+
+mod callbacks;
+mod handle;
+mod hitbox;
+mod input;
+mod playback;
+mod plugin;
+mod state_machine;
+
+#[cfg(feature = "audio")]
+mod audio;
+
+pub use handle::AnimatedSpriteHandle;
+pub use input::{InputAction, InputBindings, InputEffect, InputSource};
+pub use plugin::MapRuntimePlugin;
@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+use bevy_map_animation::{
+    AnimatedSprite, AnimationTriggerEvent, AnimationWindowEvent, HitboxShape, MapProject, TriggerDef, WindowPhase,
+    WindowTracker,
+};
+
+use crate::handle::AnimatedSpriteHandle;
+
+/// Once `handle.project` has finished loading, adds a running
+/// [`AnimatedSprite`] + a `Sprite` showing `sheet_name`'s texture, already
+/// playing `start_animation`, next to the [`AnimatedSpriteHandle`] (which
+/// stays: `advance_playback` reads it).
+pub(crate) fn resolve_sprite_handles(
+    mut commands: Commands,
+    projects: Res<Assets<MapProject>>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(Entity, &AnimatedSpriteHandle, &mut Transform), Without<AnimatedSprite>>,
+) {
+    for (entity, handle, mut transform) in &mut query {
+        let Some(project) = projects.get(&handle.project) else {
+            continue;
+        };
+        let Some(sheet) = project.sprite_sheet(&handle.sheet_name) else {
+            warn!("sprite sheet '{}' not found in map project", handle.sheet_name);
+            continue;
+        };
+
+        let texture = asset_server.load(&sheet.texture);
+        let mut animated = AnimatedSprite::default();
+        animated.play(handle.start_animation.clone());
+
+        // Set `scale` on the entity's existing `Transform` rather than
+        // inserting a fresh one - `insert` replaces the whole component, which
+        // would silently zero out the translation/rotation callers set at
+        // spawn time.
+        transform.scale = Vec3::splat(handle.scale);
+
+        commands.entity(entity).insert((
+            animated,
+            Sprite {
+                image: texture,
+                custom_size: None,
+                ..default()
+            },
+        ));
+    }
+}
+
+/// A window entering/staying in/leaving its active range this frame, plus
+/// whatever hitbox shape it carries so `spawn_and_despawn_hitboxes` doesn't
+/// need to re-look-up the animation definition.
+#[derive(Debug, Clone)]
+pub(crate) struct WindowTransition {
+    pub name: String,
+    pub phase: WindowPhase,
+    pub hitbox: Option<HitboxShape>,
+    pub layer_mask: u32,
+}
+
+/// Per-entity output of one frame's [`AnimatedSprite::tick`], carried from
+/// `advance_playback` to the systems that turn it into messages
+/// (`dispatch_trigger_and_window_events`, the hitbox system).
+#[derive(Message, Debug, Clone)]
+pub(crate) struct FrameOutcome {
+    pub entity: Entity,
+    pub animation: String,
+    pub fired_triggers: Vec<TriggerDef>,
+    pub window_transitions: Vec<WindowTransition>,
+    pub completed: bool,
+}
+
+/// Drives every playing [`AnimatedSprite`] forward by `Time::delta`, looking
+/// up its current animation's timeline in the loaded [`MapProject`].
+pub(crate) fn advance_playback(
+    time: Res<Time>,
+    projects: Res<Assets<MapProject>>,
+    mut query: Query<(Entity, &mut AnimatedSprite, &AnimatedSpriteHandle)>,
+    mut outcomes: MessageWriter<FrameOutcome>,
+) {
+    let dt_ms = time.delta_secs() * 1000.0;
+    for (entity, mut animated, handle) in &mut query {
+        if !animated.playing {
+            continue;
+        }
+        let Some(animation) = animated.current_animation.clone() else {
+            continue;
+        };
+        let Some(project) = projects.get(&handle.project) else {
+            continue;
+        };
+        let Some(sheet) = project.sprite_sheet(&handle.sheet_name) else {
+            continue;
+        };
+        let Some(anim_def) = sheet.animation(&animation) else {
+            continue;
+        };
+
+        let outcome = animated.tick(dt_ms, anim_def);
+        if outcome.fired_triggers.is_empty() && outcome.window_transitions.is_empty() && !outcome.completed {
+            continue;
+        }
+
+        let window_transitions = outcome
+            .window_transitions
+            .into_iter()
+            .map(|(name, phase)| {
+                let window_def = anim_def.windows.iter().find(|w| w.name == name);
+                WindowTransition {
+                    name,
+                    phase,
+                    hitbox: window_def.and_then(|w| w.hitbox),
+                    layer_mask: window_def.map(|w| w.layer_mask).unwrap_or(0),
+                }
+            })
+            .collect();
+
+        outcomes.write(FrameOutcome {
+            entity,
+            animation,
+            fired_triggers: outcome.fired_triggers,
+            window_transitions,
+            completed: outcome.completed,
+        });
+    }
+}
+
+/// Turns each frame's [`FrameOutcome`] into the public
+/// [`AnimationTriggerEvent`]/[`AnimationWindowEvent`] messages, and keeps
+/// each entity's [`WindowTracker`] in sync with which windows are open.
+/// Window events are only sent for entities that have a [`WindowTracker`].
+pub(crate) fn dispatch_trigger_and_window_events(
+    mut frames: MessageReader<FrameOutcome>,
+    mut trigger_events: MessageWriter<AnimationTriggerEvent>,
+    mut window_events: MessageWriter<AnimationWindowEvent>,
+    mut trackers: Query<&mut WindowTracker>,
+) {
+    for frame in frames.read() {
+        for trigger in &frame.fired_triggers {
+            trigger_events.write(AnimationTriggerEvent {
+                entity: frame.entity,
+                animation: frame.animation.clone(),
+                trigger_name: trigger.name.clone(),
+            });
+        }
+
+        for transition in &frame.window_transitions {
+            let Ok(mut tracker) = trackers.get_mut(frame.entity) else {
+                continue;
+            };
+            match transition.phase {
+                WindowPhase::Begin => tracker.open_window(transition.name.clone()),
+                WindowPhase::End => tracker.close_window(&transition.name),
+                WindowPhase::Tick => {}
+            }
+
+            window_events.write(AnimationWindowEvent {
+                entity: frame.entity,
+                animation: frame.animation.clone(),
+                window_name: transition.name.clone(),
+                phase: transition.phase,
+            });
+        }
+    }
+}
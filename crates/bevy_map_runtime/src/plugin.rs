@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bevy_map_animation::{AnimationTriggerEvent, AnimationWindowEvent, HitboxCollisionEvent, MapProject};
+
+use crate::hitbox;
+use crate::input::{self, InputBindings};
+use crate::playback::{self, FrameOutcome};
+use crate::state_machine;
+
+#[cfg(feature = "audio")]
+use crate::audio;
+
+/// Registers every system and message type `bevy_map_editor` animations
+/// need: input binding resolution, sprite-handle resolution, timeline
+/// playback, trigger/window dispatch, trigger callbacks, window-driven
+/// hitboxes, state machine transitions, and (with the `audio` feature)
+/// trigger-driven SFX.
+pub struct MapRuntimePlugin;
+
+impl Plugin for MapRuntimePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<MapProject>()
+            .init_asset_loader::<bevy_map_animation::MapProjectLoader>()
+            .init_resource::<InputBindings>()
+            .add_message::<FrameOutcome>()
+            .add_message::<AnimationTriggerEvent>()
+            .add_message::<AnimationWindowEvent>()
+            .add_message::<HitboxCollisionEvent>()
+            .add_systems(
+                Update,
+                (
+                    input::resolve_input_bindings,
+                    playback::resolve_sprite_handles,
+                    playback::advance_playback,
+                    playback::dispatch_trigger_and_window_events,
+                    (
+                        crate::callbacks::dispatch_trigger_callbacks,
+                        hitbox::spawn_and_despawn_hitboxes,
+                        state_machine::drive_state_machines,
+                    ),
+                    hitbox::sweep_hitbox_overlaps,
+                )
+                    .chain(),
+            );
+
+        #[cfg(feature = "audio")]
+        app.add_systems(
+            Update,
+            audio::spawn_audio_on_triggers.after(playback::advance_playback),
+        );
+    }
+}
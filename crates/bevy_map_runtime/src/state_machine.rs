@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use bevy_map_animation::{AnimatedSprite, AnimationStateMachine, AnimationTriggerEvent};
+
+use crate::playback::FrameOutcome;
+
+/// Feeds fired triggers and per-entity completion into every
+/// [`AnimationStateMachine`], then applies the resulting `play()` call to
+/// that entity's [`AnimatedSprite`] if a transition fired this frame.
+pub(crate) fn drive_state_machines(
+    mut frames: MessageReader<FrameOutcome>,
+    mut triggers: MessageReader<AnimationTriggerEvent>,
+    mut machines: Query<(Entity, &mut AnimationStateMachine, &mut AnimatedSprite)>,
+) {
+    let mut completed: HashSet<Entity> = HashSet::new();
+    for frame in frames.read() {
+        if frame.completed {
+            completed.insert(frame.entity);
+        }
+    }
+
+    for trigger in triggers.read() {
+        if let Ok((_, mut machine, _)) = machines.get_mut(trigger.entity) {
+            machine.fire(trigger.trigger_name.clone());
+        }
+    }
+
+    for (entity, mut machine, mut sprite) in &mut machines {
+        if let Some(animation) = machine.advance(completed.contains(&entity)) {
+            sprite.play(animation);
+        }
+    }
+}
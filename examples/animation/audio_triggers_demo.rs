@@ -0,0 +1,93 @@
+//! Audio Triggers Demo - Shows triggers wired directly to sound effects
+//!
+//! This example demonstrates:
+//! - Triggers whose `.map.json` definition carries an `AudioTrigger` (asset, volume, pitch)
+//! - `MapRuntimePlugin` spawning `AudioPlayer`/`AudioSource` automatically when such a
+//!   trigger fires - no `handle_events` glue required in the app itself
+//! - Optional spatial falloff driven by the sprite's `Transform`
+//!
+//! The "tongue" animation's "show_blurb" trigger in example_project.map.json now also
+//! carries an `AudioTrigger { asset: "sfx/tongue_snap.ogg", volume: 0.8, pitch: 1.0, spatial: true }`,
+//! so playing "tongue" plays its sound with zero per-app code.
+//!
+//! Controls:
+//! - 1: Play "walk" animation
+//! - 2: Play "croak" animation (has an audio trigger)
+//! - 3: Play "tongue" animation (has an audio trigger on "show_blurb")
+//! - Space: Stop animation
+//!
+//! Run with: cargo run --example animation_audio_triggers_demo -p bevy_map_editor_examples
+
+use bevy::prelude::*;
+use bevy_map_animation::{AnimatedSprite, AnimationTriggerEvent};
+use bevy_map_runtime::{AnimatedSpriteHandle, MapRuntimePlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Audio Triggers Demo".to_string(),
+                resolution: (800, 600).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(MapRuntimePlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (handle_input, log_triggers))
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    // ==========================================================================
+    // ONE LINE: Load animated sprite from map project
+    // ==========================================================================
+    // Any trigger in the project that carries an `AudioTrigger` plays its clip
+    // automatically - MapRuntimePlugin handles spawning the AudioPlayer for you.
+    commands.spawn((
+        AnimatedSpriteHandle::new(
+            asset_server.load("maps/example_project.map.json"),
+            "Frog",
+            "croak", // Start with croak - it has an audio trigger
+        )
+        .with_scale(4.0),
+        Transform::from_xyz(0.0, 50.0, 0.0),
+    ));
+
+    info!("Audio Triggers Demo - sounds play automatically, no handle_events needed!");
+}
+
+fn handle_input(keyboard: Res<ButtonInput<KeyCode>>, mut query: Query<&mut AnimatedSprite>) {
+    let animation = if keyboard.just_pressed(KeyCode::Digit1) {
+        Some("walk")
+    } else if keyboard.just_pressed(KeyCode::Digit2) {
+        Some("croak")
+    } else if keyboard.just_pressed(KeyCode::Digit3) {
+        Some("tongue")
+    } else {
+        None
+    };
+
+    let stop = keyboard.just_pressed(KeyCode::Space);
+
+    if let Ok(mut animated) = query.single_mut() {
+        if let Some(name) = animation {
+            animated.play(name);
+            info!("Playing: {}", name);
+        }
+        if stop {
+            animated.stop();
+            info!("Stopped");
+        }
+    }
+}
+
+/// Triggers still fire the regular event too, so you can combine SFX with
+/// your own game logic - the audio playback just no longer needs it.
+fn log_triggers(mut triggers: MessageReader<AnimationTriggerEvent>) {
+    for event in triggers.read() {
+        info!("Trigger fired: {} ({})", event.trigger_name, event.animation);
+    }
+}
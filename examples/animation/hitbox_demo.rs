@@ -0,0 +1,95 @@
+//! Hitbox Demo - Windows that drive real colliders and emit collision events
+//!
+//! This example demonstrates:
+//! - A window definition (`enable_hitbox` on the "tongue" animation) carrying a
+//!   rectangle/circle shape in sprite-local space plus a layer mask
+//! - `MapRuntimePlugin` spawning a child collider entity on `WindowPhase::Begin`,
+//!   despawning it on `WindowPhase::End`, and sweeping it against `Hurtbox`
+//!   entities every `WindowPhase::Tick`
+//! - `HitboxCollisionEvent` firing once per overlapping hurtbox per
+//!   window-activation (repeats are deduped via `WindowTracker`)
+//!
+//! Controls:
+//! - 3: Play "tongue" animation (opens a hitbox for "enable_hitbox")
+//! - Space: Stop animation
+//!
+//! Run with: cargo run --example animation_hitbox_demo -p bevy_map_editor_examples
+
+use bevy::prelude::*;
+use bevy_map_animation::{AnimatedSprite, HitboxCollisionEvent, Hurtbox, WindowTracker};
+use bevy_map_runtime::{AnimatedSpriteHandle, MapRuntimePlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Hitbox Demo".to_string(),
+                resolution: (800, 600).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(MapRuntimePlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, (handle_input, handle_collisions))
+        .run();
+}
+
+#[derive(Component)]
+struct Dummy;
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    // Attacker: the frog's "tongue" animation opens a hitbox window.
+    commands.spawn((
+        AnimatedSpriteHandle::new(
+            asset_server.load("maps/example_project.map.json"),
+            "Frog",
+            "tongue",
+        )
+        .with_scale(4.0),
+        WindowTracker::default(), // Required for window-driven hitboxes
+        Transform::from_xyz(-80.0, 50.0, 0.0),
+    ));
+
+    // Victim: a plain hurtbox sitting in the tongue's path.
+    commands.spawn((
+        Sprite::from_color(Color::srgb(0.8, 0.2, 0.2), Vec2::splat(32.0)),
+        Transform::from_xyz(40.0, 50.0, 0.0),
+        Hurtbox::rect(Vec2::ZERO, Vec2::splat(32.0)),
+        Dummy,
+    ));
+
+    info!("Hitbox Demo - play 'tongue' and watch for HitboxCollisionEvent!");
+}
+
+fn handle_input(keyboard: Res<ButtonInput<KeyCode>>, mut query: Query<&mut AnimatedSprite>) {
+    let animation = if keyboard.just_pressed(KeyCode::Digit3) {
+        Some("tongue")
+    } else {
+        None
+    };
+
+    let stop = keyboard.just_pressed(KeyCode::Space);
+
+    if let Ok(mut animated) = query.single_mut() {
+        if let Some(name) = animation {
+            animated.play(name);
+            info!("Playing: {}", name);
+        }
+        if stop {
+            animated.stop();
+            info!("Stopped");
+        }
+    }
+}
+
+fn handle_collisions(mut hits: MessageReader<HitboxCollisionEvent>) {
+    for hit in hits.read() {
+        info!(
+            "Hit! {:?} -> {:?} via '{}' at {:?}",
+            hit.attacker, hit.victim, hit.window_name, hit.world_point
+        );
+    }
+}
@@ -0,0 +1,103 @@
+//! Input Bindings Demo - Abstract actions instead of hardcoded KeyCodes
+//!
+//! This example demonstrates:
+//! - `InputBindings` mapping abstract action names to input sources (keyboard
+//!   keys here) and to an effect (`PlayAnimation`, `StopAnimation`,
+//!   `SetStateMachineParam`)
+//! - "Just pressed" vs "held" semantics configured per binding
+//! - `actions.just_fired("play_tongue")` replacing the hardcoded
+//!   `Digit1..Digit4`/`Space` matching in animation_triggers_demo.rs's
+//!   `handle_input`
+//!
+//! Bindings can also be authored in the `.map.json` file itself, as
+//! `MapProject::input_bindings`, and loaded with `InputBindings::load` -
+//! this demo builds them in code instead to keep the example self-contained.
+//!
+//! Controls:
+//! - 1/2/3/4: Play walk/croak/tongue/hit (bound to "play_walk" etc.)
+//! - Space: Stop animation (bound to "stop")
+//!
+//! Run with: cargo run --example animation_input_bindings_demo -p bevy_map_editor_examples
+
+use bevy::prelude::*;
+use bevy_map_animation::AnimatedSprite;
+use bevy_map_runtime::{
+    AnimatedSpriteHandle, InputAction, InputBindings, InputEffect, InputSource, MapRuntimePlugin,
+};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Input Bindings Demo".to_string(),
+                resolution: (800, 600).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(MapRuntimePlugin)
+        .insert_resource(setup_bindings())
+        .add_systems(Startup, setup)
+        .add_systems(Update, log_fired_actions)
+        .run();
+}
+
+fn setup_bindings() -> InputBindings {
+    let mut bindings = InputBindings::default();
+    bindings.bind(
+        InputAction::new("play_walk"),
+        InputSource::Key(KeyCode::Digit1),
+        InputEffect::PlayAnimation("walk".into()),
+    );
+    bindings.bind(
+        InputAction::new("play_croak"),
+        InputSource::Key(KeyCode::Digit2),
+        InputEffect::PlayAnimation("croak".into()),
+    );
+    bindings.bind(
+        InputAction::new("play_tongue"),
+        InputSource::Key(KeyCode::Digit3),
+        InputEffect::PlayAnimation("tongue".into()),
+    );
+    bindings.bind(
+        InputAction::new("play_hit"),
+        InputSource::Key(KeyCode::Digit4),
+        InputEffect::PlayAnimation("hit".into()),
+    );
+    bindings.bind(
+        InputAction::new("stop"),
+        InputSource::Key(KeyCode::Space),
+        InputEffect::StopAnimation,
+    );
+    bindings
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    commands.spawn((
+        AnimatedSpriteHandle::new(
+            asset_server.load("maps/example_project.map.json"),
+            "Frog",
+            "walk",
+        )
+        .with_scale(4.0),
+        Transform::from_xyz(0.0, 50.0, 0.0),
+    ));
+
+    info!("Input Bindings Demo - 1/2/3/4 play, Space stops, all data-driven");
+}
+
+/// `MapRuntimePlugin` already resolves bindings into effects and applies them
+/// to the targeted `AnimatedSprite`; this just demonstrates querying the
+/// resolved actions for custom handling.
+fn log_fired_actions(bindings: Res<InputBindings>, query: Query<&AnimatedSprite>) {
+    if bindings.just_fired("play_tongue")
+        && let Ok(animated) = query.single()
+    {
+        info!(
+            "play_tongue fired, now playing: {}",
+            animated.current_animation.as_deref().unwrap_or("none")
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! State Machine Demo - Data-driven animation transitions instead of raw keypresses
+//!
+//! This example demonstrates:
+//! - An `AnimationStateMachine` describing states (each mapping to an animation
+//!   name), transitions with conditions, and the triggers that fire them
+//! - Transitions sourced from an `AnimationTriggerEvent`, from animation
+//!   completion, or from a user-set param in the machine's `HashMap<String, ParamValue>`
+//! - An "any-state" transition set (here, `hit`) evaluated first so it can
+//!   interrupt any other state
+//! - `machine.set_param` / `machine.fire` replacing the raw `Digit1..4`/`Space`
+//!   handling from animation_triggers_demo.rs's `handle_input`
+//!
+//! Controls:
+//! - G: Toggle the "grounded" param (walk only plays while grounded)
+//! - Space: Fire the "jump" trigger
+//! - H: Fire the "hit" trigger (interrupts anything, via the any-state set)
+//!
+//! Run with: cargo run --example animation_state_machine_demo -p bevy_map_editor_examples
+
+use bevy::prelude::*;
+use bevy_map_animation::{
+    AnimationStateMachine, ParamValue, StateDef, TransitionCondition, TransitionDef,
+};
+use bevy_map_runtime::{AnimatedSpriteHandle, MapRuntimePlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "State Machine Demo".to_string(),
+                resolution: (800, 600).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(MapRuntimePlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, handle_input)
+        .run();
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    let mut machine = AnimationStateMachine::new("idle");
+    machine.add_state(StateDef::new("idle", "croak"));
+    machine.add_state(StateDef::new("walk", "walk"));
+    machine.add_state(StateDef::new("jump", "tongue"));
+    machine.add_state(StateDef::new("hit", "hit"));
+
+    machine.add_transition(
+        "idle",
+        TransitionDef::new("walk", TransitionCondition::Param("grounded".to_string(), ParamValue::Bool(true))),
+    );
+    machine.add_transition(
+        "walk",
+        TransitionDef::new("idle", TransitionCondition::Param("grounded".to_string(), ParamValue::Bool(false))),
+    );
+    machine.add_transition("idle", TransitionDef::new("jump", TransitionCondition::Trigger("jump".to_string())));
+    machine.add_transition("walk", TransitionDef::new("jump", TransitionCondition::Trigger("jump".to_string())));
+    machine.add_transition("jump", TransitionDef::new("idle", TransitionCondition::OnComplete));
+
+    // Any-state transition: a "hit" trigger interrupts whatever is playing.
+    machine.add_any_state_transition(TransitionDef::new("hit", TransitionCondition::Trigger("hit".to_string())));
+    machine.add_transition("hit", TransitionDef::new("idle", TransitionCondition::OnComplete));
+
+    machine.set_param("grounded", ParamValue::Bool(true));
+
+    commands.spawn((
+        AnimatedSpriteHandle::new(
+            asset_server.load("maps/example_project.map.json"),
+            "Frog",
+            "croak",
+        )
+        .with_scale(4.0),
+        machine,
+        Transform::from_xyz(0.0, 50.0, 0.0),
+    ));
+
+    info!("State Machine Demo - G: toggle grounded, Space: jump, H: hit");
+}
+
+fn handle_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut query: Query<&mut AnimationStateMachine>,
+) {
+    let Ok(mut machine) = query.single_mut() else {
+        return;
+    };
+
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        let grounded = !matches!(machine.param("grounded"), Some(ParamValue::Bool(true)));
+        machine.set_param("grounded", ParamValue::Bool(grounded));
+        info!("grounded = {}", grounded);
+    }
+
+    if keyboard.just_pressed(KeyCode::Space) {
+        machine.fire("jump");
+        info!("fired: jump");
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        machine.fire("hit");
+        info!("fired: hit");
+    }
+}
@@ -0,0 +1,104 @@
+//! Trigger Callbacks Demo - Binds trigger names straight to one-shot systems
+//!
+//! This example demonstrates:
+//! - `TriggerRegistrationExt::on_trigger` (on `EntityCommands`) registering a
+//!   callback for a named trigger; `on_trigger_in` scopes it to one animation
+//! - `MapRuntimePlugin`'s dispatch system invoking the matching one-shot system
+//!   when the trigger fires, instead of every app writing its own
+//!   `MessageReader<AnimationTriggerEvent>` + string-match loop (see
+//!   animation_triggers_demo.rs)
+//!
+//! The "tongue" animation's "show_blurb" trigger spawns a text blurb above the
+//! frog the moment it fires - no manual event polling required.
+//!
+//! Controls:
+//! - 1: Play "walk" animation
+//! - 2: Play "croak" animation
+//! - 3: Play "tongue" animation (fires "show_blurb")
+//! - Space: Stop animation
+//!
+//! Run with: cargo run --example animation_trigger_callbacks_demo -p bevy_map_editor_examples
+
+use bevy::prelude::*;
+use bevy_map_animation::{AnimatedSprite, TriggerContext, TriggerRegistrationExt};
+use bevy_map_runtime::{AnimatedSpriteHandle, MapRuntimePlugin};
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Trigger Callbacks Demo".to_string(),
+                resolution: (800, 600).into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(MapRuntimePlugin)
+        .add_systems(Startup, setup)
+        .add_systems(Update, handle_input)
+        .run();
+}
+
+#[derive(Component)]
+struct Blurb;
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(Camera2d);
+
+    let sprite = commands
+        .spawn((
+            AnimatedSpriteHandle::new(
+                asset_server.load("maps/example_project.map.json"),
+                "Frog",
+                "tongue", // Start with tongue - it fires "show_blurb"
+            )
+            .with_scale(4.0),
+            Transform::from_xyz(0.0, 50.0, 0.0),
+        ))
+        .id();
+
+    // ==========================================================================
+    // ONE LINE: Bind a trigger directly to a spawn callback
+    // ==========================================================================
+    // This replaces the 10-line handle_events match loop from
+    // animation_triggers_demo.rs with colocated, declarative setup.
+    commands
+        .entity(sprite)
+        .on_trigger("show_blurb", spawn_blurb);
+
+    info!("Trigger Callbacks Demo - play 'tongue' to see show_blurb fire!");
+}
+
+fn spawn_blurb(In((entity, ctx)): In<(Entity, TriggerContext)>, mut commands: Commands) {
+    info!("show_blurb fired for {:?} ({})", entity, ctx.animation);
+    commands.spawn((
+        Text2d::new("Ribbit!"),
+        Transform::from_xyz(0.0, 120.0, 0.0),
+        Blurb,
+    ));
+}
+
+fn handle_input(keyboard: Res<ButtonInput<KeyCode>>, mut query: Query<&mut AnimatedSprite>) {
+    let animation = if keyboard.just_pressed(KeyCode::Digit1) {
+        Some("walk")
+    } else if keyboard.just_pressed(KeyCode::Digit2) {
+        Some("croak")
+    } else if keyboard.just_pressed(KeyCode::Digit3) {
+        Some("tongue")
+    } else {
+        None
+    };
+
+    let stop = keyboard.just_pressed(KeyCode::Space);
+
+    if let Ok(mut animated) = query.single_mut() {
+        if let Some(name) = animation {
+            animated.play(name);
+            info!("Playing: {}", name);
+        }
+        if stop {
+            animated.stop();
+            info!("Stopped");
+        }
+    }
+}